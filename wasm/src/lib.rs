@@ -8,6 +8,7 @@ use std::{
 use async_recursion::async_recursion;
 use futures::Future;
 use lazy_static::lazy_static;
+use rstar::{PointDistance, RTree, RTreeObject, AABB};
 use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::spawn_local;
@@ -31,13 +32,102 @@ extern "C" {
 pub struct Point {
     pub x: i32,
     pub y: i32,
+    #[serde(default)]
+    pub z: i32,
 }
 
 impl Point {
     pub fn distance(&self, other: &Point) -> f32 {
-        (((other.x - self.x).pow(2)
-            + (other.y - self.y).pow(2)) as f32)
-            .sqrt()
+        self.distance_squared(other).sqrt()
+    }
+
+    pub fn distance_squared(&self, other: &Point) -> f32 {
+        ((other.x - self.x).pow(2)
+            + (other.y - self.y).pow(2)
+            + (other.z - self.z).pow(2)) as f32
+    }
+
+    pub fn manhattan_distance(&self, other: &Point) -> f32 {
+        ((other.x - self.x).abs()
+            + (other.y - self.y).abs()
+            + (other.z - self.z).abs()) as f32
+    }
+}
+
+/// Distance metric used to score candidate moves. Search functions
+/// consume a `Metric` instead of calling `Point::distance` directly,
+/// so callers can trade accuracy for speed (e.g. skip the `sqrt` in
+/// comparison-only code paths with `SquaredEuclidean`) or switch to a
+/// grid-aligned metric, without the returned path format changing.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Metric {
+    Euclidean,
+    SquaredEuclidean,
+    Manhattan,
+}
+
+impl Metric {
+    fn from_js(value: &JsValue) -> Metric {
+        match value.as_string().as_deref() {
+            Some("squared_euclidean") => {
+                Metric::SquaredEuclidean
+            }
+            Some("manhattan") => Metric::Manhattan,
+            _ => Metric::Euclidean,
+        }
+    }
+
+    fn distance(&self, a: &Point, b: &Point) -> f32 {
+        match self {
+            Metric::Euclidean => a.distance(b),
+            Metric::SquaredEuclidean => {
+                a.distance_squared(b)
+            }
+            Metric::Manhattan => a.manhattan_distance(b),
+        }
+    }
+}
+
+/// Full pairwise distance table, built once per search under the
+/// selected `Metric` so every lookup afterwards is a single array
+/// index instead of a fresh `sqrt`. Most valuable to `naive_search` and
+/// `closest_search`, which otherwise recompute the same distances over
+/// and over across recursive calls.
+struct DistanceMatrix {
+    n: usize,
+    distances: Vec<f32>,
+}
+
+impl DistanceMatrix {
+    fn build(
+        destinations: &[Point],
+        metric: Metric,
+    ) -> DistanceMatrix {
+        let n = destinations.len();
+        let mut distances = vec![0.; n * n];
+        for i in 0..n {
+            for j in (i + 1)..n {
+                let d = metric
+                    .distance(&destinations[i], &destinations[j]);
+                distances[i * n + j] = d;
+                distances[j * n + i] = d;
+            }
+        }
+        DistanceMatrix { n, distances }
+    }
+
+    /// Placeholder for algorithms that take a `&DistanceMatrix` for
+    /// dispatch-signature uniformity but never read from it, so callers
+    /// don't pay the O(n^2) build cost for no benefit.
+    fn unused() -> DistanceMatrix {
+        DistanceMatrix {
+            n: 0,
+            distances: Vec::new(),
+        }
+    }
+
+    fn at(&self, i: usize, j: usize) -> f32 {
+        self.distances[i * self.n + j]
     }
 }
 
@@ -84,16 +174,37 @@ impl Future for Frame {
 pub fn find_shortest(
     algorithm: JsValue,
     destinations: JsValue,
+    beam_width: JsValue,
+    metric: JsValue,
 ) -> JsValue {
     if !algorithm.is_string() {
         alert("Algorithm needs to be a string");
         return JsValue::NULL;
     }
 
+    let metric = Metric::from_js(&metric);
+
     match serde_wasm_bindgen::from_value::<Vec<Point>>(
         destinations,
     ) {
         Ok(destinations) => {
+            let algorithm_name = algorithm.as_string().unwrap();
+            let mut stats = Stats::new(&algorithm_name);
+
+            if algorithm_name == "beam" {
+                let shortest_path = beam_search(
+                    &destinations,
+                    beam_width_from(&beam_width),
+                    &mut stats,
+                    metric,
+                );
+
+                return serde_wasm_bindgen::to_value(
+                    &shortest_path,
+                )
+                .unwrap();
+            }
+
             let mut visited =
                 Vec::with_capacity(destinations.len());
             visited.push(0);
@@ -101,9 +212,17 @@ pub fn find_shortest(
             let mut shortest_path =
                 Vec::with_capacity(destinations.len());
 
-            (match algorithm.as_string().unwrap().as_str() {
+            let matrix = if algorithm_name == "branch_bound" {
+                DistanceMatrix::unused()
+            } else {
+                DistanceMatrix::build(&destinations, metric)
+            };
+
+            (match algorithm_name.as_str() {
                 "naive" => naive_search,
-                "closest" => closest_search,
+                "closest" => closest_search_rtree,
+                "closest_2opt" => closest_2opt_search,
+                "branch_bound" => branch_bound_search,
                 _ => {
                     alert("Unknown algorithm");
                     return JsValue::NULL;
@@ -113,6 +232,9 @@ pub fn find_shortest(
                 &mut visited,
                 &mut shortest_path,
                 &mut f32::MAX.clone(),
+                &mut stats,
+                metric,
+                &matrix,
             );
 
             serde_wasm_bindgen::to_value(&shortest_path)
@@ -130,24 +252,32 @@ fn naive_search(
     visited: &mut Vec<usize>,
     shortest_path: &mut Vec<usize>,
     shortest_length: &mut f32,
+    stats: &mut Stats,
+    _metric: Metric,
+    matrix: &DistanceMatrix,
 ) {
+    stats.nodes_explored += 1;
+    maybe_send_search_state(
+        stats,
+        visited.len(),
+        destinations.len(),
+        1,
+    );
+
     if visited.len() >= destinations.len() {
         // Reached the end
         let mut length = 0.;
-        let mut last_dest = destinations.first().unwrap();
-        for dest in visited
-            .iter()
-            .skip(1)
-            .map(|i| destinations.get(*i).unwrap())
-        {
-            length += last_dest.distance(dest);
+        let mut last = visited[0];
+        for &i in visited.iter().skip(1) {
+            length += matrix.at(last, i);
             if length > *shortest_length {
                 return;
             }
-            last_dest = dest;
+            last = i;
         }
 
         *shortest_length = length;
+        stats.best_length = length;
 
         shortest_path.clear();
         shortest_path.extend(visited.iter());
@@ -165,6 +295,9 @@ fn naive_search(
             visited,
             shortest_path,
             shortest_length,
+            stats,
+            _metric,
+            matrix,
         );
         visited.pop();
     }
@@ -175,22 +308,32 @@ fn closest_search(
     visited: &mut Vec<usize>,
     shortest_path: &mut Vec<usize>,
     shortest_length: &mut f32,
+    stats: &mut Stats,
+    metric: Metric,
+    matrix: &DistanceMatrix,
 ) {
+    stats.nodes_explored += 1;
+    maybe_send_search_state(
+        stats,
+        visited.len(),
+        destinations.len(),
+        1,
+    );
+
     if visited.len() >= destinations.len() {
         // Reached the end
         shortest_path.extend(visited.iter());
+        stats.best_length =
+            tour_length(destinations, visited, metric);
 
         return;
     }
-    let last =
-        destinations.get(*visited.last().unwrap()).unwrap();
+    let last = *visited.last().unwrap();
 
     let mut not_visited: Vec<(usize, f32)> = (1
         ..destinations.len())
         .filter(|i| !visited.contains(i))
-        .map(|i| {
-            (i, last.distance(destinations.get(i).unwrap()))
-        })
+        .map(|i| (i, matrix.at(last, i)))
         .collect();
 
     not_visited
@@ -202,24 +345,616 @@ fn closest_search(
         visited,
         shortest_path,
         shortest_length,
+        stats,
+        metric,
+        matrix,
+    );
+    visited.pop();
+}
+
+/// Minimum number of destinations before building an R-tree pays for
+/// itself; below this, the brute-force scan in `closest_search` is
+/// cheaper than the tree construction it would replace.
+const RTREE_THRESHOLD: usize = 32;
+
+#[derive(Clone, Copy)]
+struct IndexedPoint {
+    index: usize,
+    point: Point,
+}
+
+impl RTreeObject for IndexedPoint {
+    type Envelope = AABB<[f32; 3]>;
+
+    fn envelope(&self) -> Self::Envelope {
+        AABB::from_point([
+            self.point.x as f32,
+            self.point.y as f32,
+            self.point.z as f32,
+        ])
+    }
+}
+
+impl PointDistance for IndexedPoint {
+    fn distance_2(&self, other: &[f32; 3]) -> f32 {
+        let dx = self.point.x as f32 - other[0];
+        let dy = self.point.y as f32 - other[1];
+        let dz = self.point.z as f32 - other[2];
+        dx * dx + dy * dy + dz * dz
+    }
+}
+
+/// Same greedy nearest-neighbor tour as `closest_search`, but looks up
+/// the next destination with an `rstar` R-tree instead of re-sorting a
+/// full distance vector at every recursion level. The tree only ever
+/// ranks candidates by Euclidean distance, so for any other `metric`
+/// that ranking would silently disagree with the reported tour cost —
+/// falls back to the brute-force scan in that case, and also for small
+/// inputs where building the tree isn't worth it.
+fn closest_search_rtree(
+    destinations: &Vec<Point>,
+    visited: &mut Vec<usize>,
+    shortest_path: &mut Vec<usize>,
+    shortest_length: &mut f32,
+    stats: &mut Stats,
+    metric: Metric,
+    matrix: &DistanceMatrix,
+) {
+    if destinations.len() < RTREE_THRESHOLD
+        || metric != Metric::Euclidean
+    {
+        closest_search(
+            destinations,
+            visited,
+            shortest_path,
+            shortest_length,
+            stats,
+            metric,
+            matrix,
+        );
+        return;
+    }
+
+    let tree: RTree<IndexedPoint> = RTree::bulk_load(
+        destinations
+            .iter()
+            .enumerate()
+            .map(|(index, &point)| IndexedPoint {
+                index,
+                point,
+            })
+            .collect(),
+    );
+
+    closest_search_rtree_step(
+        destinations,
+        &tree,
+        visited,
+        shortest_path,
+        shortest_length,
+        stats,
+        metric,
+    );
+}
+
+/// Looks up the nearest unvisited destination via the R-tree. Only
+/// ever called with `Metric::Euclidean` (see `closest_search_rtree`),
+/// so the tree's Euclidean-distance ranking and the reported tour
+/// cost always agree.
+fn closest_search_rtree_step(
+    destinations: &Vec<Point>,
+    tree: &RTree<IndexedPoint>,
+    visited: &mut Vec<usize>,
+    shortest_path: &mut Vec<usize>,
+    shortest_length: &mut f32,
+    stats: &mut Stats,
+    metric: Metric,
+) {
+    stats.nodes_explored += 1;
+    maybe_send_search_state(
+        stats,
+        visited.len(),
+        destinations.len(),
+        1,
+    );
+
+    if visited.len() >= destinations.len() {
+        // Reached the end
+        shortest_path.extend(visited.iter());
+        stats.best_length =
+            tour_length(destinations, visited, metric);
+
+        return;
+    }
+
+    let last =
+        destinations.get(*visited.last().unwrap()).unwrap();
+    let query =
+        [last.x as f32, last.y as f32, last.z as f32];
+
+    let next = tree
+        .nearest_neighbor_iter(&query)
+        .map(|candidate| candidate.index)
+        .find(|index| !visited.contains(index))
+        .unwrap();
+
+    visited.push(next);
+    closest_search_rtree_step(
+        destinations,
+        tree,
+        visited,
+        shortest_path,
+        shortest_length,
+        stats,
+        metric,
     );
     visited.pop();
 }
 
+/// Repeatedly uncrosses edges in `path` until a full pass finds no
+/// improvement. For every pair of edges `(path[i], path[i+1])` and
+/// `(path[j], path[j+1])` with `i < j`, reversing the segment
+/// `path[i+1..=j]` is kept whenever it shortens the tour. Index 0 is
+/// never touched by a reversal, so the start stays fixed.
+fn two_opt(
+    destinations: &Vec<Point>,
+    path: &mut Vec<usize>,
+    shortest_length: &mut f32,
+    metric: Metric,
+) {
+    let mut improved = true;
+    while improved {
+        improved = false;
+
+        for i in 0..path.len() - 1 {
+            for j in (i + 1)..path.len() - 1 {
+                let a = destinations.get(path[i]).unwrap();
+                let b = destinations.get(path[i + 1]).unwrap();
+                let c = destinations.get(path[j]).unwrap();
+                let d = destinations.get(path[j + 1]).unwrap();
+
+                let before = metric.distance(a, b)
+                    + metric.distance(c, d);
+                let after = metric.distance(a, c)
+                    + metric.distance(b, d);
+
+                if after < before {
+                    path[i + 1..=j].reverse();
+                    improved = true;
+                }
+            }
+        }
+    }
+
+    *shortest_length = tour_length(destinations, path, metric);
+}
+
+fn closest_2opt_search(
+    destinations: &Vec<Point>,
+    visited: &mut Vec<usize>,
+    shortest_path: &mut Vec<usize>,
+    shortest_length: &mut f32,
+    stats: &mut Stats,
+    metric: Metric,
+    matrix: &DistanceMatrix,
+) {
+    closest_search_rtree(
+        destinations,
+        visited,
+        shortest_path,
+        shortest_length,
+        stats,
+        metric,
+        matrix,
+    );
+    two_opt(
+        destinations,
+        shortest_path,
+        shortest_length,
+        metric,
+    );
+    stats.best_length = *shortest_length;
+}
+
+/// Sum of the edge lengths along `visited`, starting from
+/// `destinations[0]`. No closing edge back to the start is added.
+fn tour_length(
+    destinations: &[Point],
+    visited: &[usize],
+    metric: Metric,
+) -> f32 {
+    let mut length = 0.;
+    let mut last_dest = destinations.first().unwrap();
+    for dest in visited
+        .iter()
+        .skip(1)
+        .map(|i| destinations.get(*i).unwrap())
+    {
+        length += metric.distance(last_dest, dest);
+        last_dest = dest;
+    }
+    length
+}
+
+/// Admissible lower bound on the cost remaining to complete `visited`
+/// into a full tour: the weight of a minimum spanning tree (Prim's
+/// algorithm) over the not-yet-visited destinations plus the current
+/// endpoint. Any completion of the tour from `current` is itself a
+/// spanning tree of that same node set, so its cost can never be less
+/// than the MST's — the bound never overestimates the true remaining
+/// cost, and pruning on it stays exact. The tour never returns to
+/// `destinations[0]` (`tour_length` has no closing edge), so the start
+/// point is excluded rather than bounded with an extra edge back to it.
+fn mst_bound(
+    destinations: &[Point],
+    visited: &[usize],
+    key: &mut Vec<f32>,
+    metric: Metric,
+) -> f32 {
+    let current = *visited.last().unwrap();
+
+    let nodes: Vec<usize> = (1..destinations.len())
+        .filter(|i| !visited.contains(i))
+        .chain(std::iter::once(current))
+        .collect();
+
+    if nodes.len() <= 1 {
+        return 0.;
+    }
+
+    key.clear();
+    key.resize(nodes.len(), f32::MAX);
+    let mut in_tree = vec![false; nodes.len()];
+    key[0] = 0.;
+
+    let mut mst_weight = 0.;
+    for _ in 0..nodes.len() {
+        let mut u = usize::MAX;
+        let mut best = f32::MAX;
+        for (v, &k) in key.iter().enumerate() {
+            if !in_tree[v] && k < best {
+                best = k;
+                u = v;
+            }
+        }
+        if u == usize::MAX {
+            break;
+        }
+        in_tree[u] = true;
+        mst_weight += best;
+
+        let pu = &destinations[nodes[u]];
+        for (v, &n) in nodes.iter().enumerate() {
+            if !in_tree[v] {
+                let d =
+                    metric.distance(pu, &destinations[n]);
+                if d < key[v] {
+                    key[v] = d;
+                }
+            }
+        }
+    }
+
+    mst_weight
+}
+
+fn branch_bound_search(
+    destinations: &Vec<Point>,
+    visited: &mut Vec<usize>,
+    shortest_path: &mut Vec<usize>,
+    shortest_length: &mut f32,
+    stats: &mut Stats,
+    metric: Metric,
+    _matrix: &DistanceMatrix,
+) {
+    stats.nodes_explored += 1;
+    maybe_send_search_state(
+        stats,
+        visited.len(),
+        destinations.len(),
+        1,
+    );
+
+    if visited.len() >= destinations.len() {
+        // Reached the end
+        let length = tour_length(destinations, visited, metric);
+        if length < *shortest_length {
+            *shortest_length = length;
+            stats.best_length = length;
+
+            shortest_path.clear();
+            shortest_path.extend(visited.iter());
+        }
+
+        return;
+    }
+
+    let mut key = Vec::new();
+    let bound = tour_length(destinations, visited, metric)
+        + mst_bound(destinations, visited, &mut key, metric);
+    if bound >= *shortest_length {
+        stats.pruned += 1;
+        return;
+    }
+
+    let not_visited: Vec<usize> = (1..destinations.len())
+        .filter(|i| !visited.contains(i))
+        .collect();
+
+    for i in not_visited {
+        visited.push(i);
+        branch_bound_search(
+            destinations,
+            visited,
+            shortest_path,
+            shortest_length,
+            stats,
+            metric,
+            _matrix,
+        );
+        visited.pop();
+    }
+}
+
+/// Frontier width used by `beam_search` when the caller doesn't pass
+/// one (or passes something that isn't a positive number).
+const DEFAULT_BEAM_WIDTH: usize = 8;
+
+fn beam_width_from(beam_width: &JsValue) -> usize {
+    beam_width
+        .as_f64()
+        .map(|width| width as usize)
+        .filter(|&width| width > 0)
+        .unwrap_or(DEFAULT_BEAM_WIDTH)
+}
+
+#[derive(Clone)]
+struct BeamTour {
+    visited: Vec<usize>,
+    length: f32,
+}
+
+/// Cheap lower bound used only to rank successors: the distance from
+/// the tour's current endpoint to its single nearest unvisited
+/// neighbor.
+fn nearest_unvisited_distance(
+    destinations: &[Point],
+    visited: &[usize],
+    metric: Metric,
+) -> f32 {
+    let last = destinations[*visited.last().unwrap()];
+    (0..destinations.len())
+        .filter(|i| !visited.contains(i))
+        .map(|i| metric.distance(&last, &destinations[i]))
+        .fold(f32::MAX, f32::min)
+}
+
+/// Keeps a frontier of at most `width` partial tours. At each step
+/// every partial tour is expanded by appending each unvisited index,
+/// successors are scored by `length so far + nearest_unvisited_distance`
+/// and only the best `width` survive. Returns the shortest complete
+/// tour found once every surviving tour is complete.
+fn beam_search(
+    destinations: &Vec<Point>,
+    width: usize,
+    stats: &mut Stats,
+    metric: Metric,
+) -> Vec<usize> {
+    let mut frontier = vec![BeamTour {
+        visited: vec![0],
+        length: 0.,
+    }];
+
+    while frontier
+        .iter()
+        .any(|tour| tour.visited.len() < destinations.len())
+    {
+        let mut successors: Vec<(BeamTour, f32)> = Vec::new();
+
+        for tour in &frontier {
+            if tour.visited.len() >= destinations.len() {
+                successors.push((tour.clone(), tour.length));
+                continue;
+            }
+
+            let last = destinations
+                [*tour.visited.last().unwrap()];
+
+            for i in 0..destinations.len() {
+                if tour.visited.contains(&i) {
+                    continue;
+                }
+
+                let mut visited = tour.visited.clone();
+                visited.push(i);
+
+                let length = tour.length
+                    + metric.distance(&last, &destinations[i]);
+                let estimate =
+                    if visited.len() < destinations.len() {
+                        nearest_unvisited_distance(
+                            destinations,
+                            &visited,
+                            metric,
+                        )
+                    } else {
+                        0.
+                    };
+
+                stats.nodes_explored += 1;
+                successors.push((
+                    BeamTour { visited, length },
+                    length + estimate,
+                ));
+            }
+        }
+
+        successors.sort_by(|a, b| {
+            a.1.partial_cmp(&b.1).unwrap()
+        });
+        successors.truncate(width);
+
+        frontier = successors
+            .into_iter()
+            .map(|(tour, _)| tour)
+            .collect();
+
+        stats.best_length = frontier
+            .first()
+            .map(|tour| tour.length)
+            .unwrap_or(f32::MAX);
+        let depth = frontier
+            .iter()
+            .map(|tour| tour.visited.len())
+            .max()
+            .unwrap_or(0);
+        maybe_send_search_state(
+            stats,
+            depth,
+            destinations.len(),
+            frontier.len(),
+        );
+    }
+
+    frontier
+        .into_iter()
+        .min_by(|a, b| {
+            a.length.partial_cmp(&b.length).unwrap()
+        })
+        .unwrap()
+        .visited
+}
+
+async fn animated_beam_search(
+    destinations: &Vec<Point>,
+    width: usize,
+    stats: &mut Stats,
+    metric: Metric,
+) -> Vec<usize> {
+    let mut frontier = vec![BeamTour {
+        visited: vec![0],
+        length: 0.,
+    }];
+
+    while frontier
+        .iter()
+        .any(|tour| tour.visited.len() < destinations.len())
+    {
+        let mut successors: Vec<(BeamTour, f32)> = Vec::new();
+
+        for tour in &frontier {
+            if tour.visited.len() >= destinations.len() {
+                successors.push((tour.clone(), tour.length));
+                continue;
+            }
+
+            let last = destinations
+                [*tour.visited.last().unwrap()];
+
+            for i in 0..destinations.len() {
+                if tour.visited.contains(&i) {
+                    continue;
+                }
+
+                let mut visited = tour.visited.clone();
+                visited.push(i);
+
+                let length = tour.length
+                    + metric.distance(&last, &destinations[i]);
+                let estimate =
+                    if visited.len() < destinations.len() {
+                        nearest_unvisited_distance(
+                            destinations,
+                            &visited,
+                            metric,
+                        )
+                    } else {
+                        0.
+                    };
+
+                stats.nodes_explored += 1;
+                successors.push((
+                    BeamTour { visited, length },
+                    length + estimate,
+                ));
+            }
+        }
+
+        successors.sort_by(|a, b| {
+            a.1.partial_cmp(&b.1).unwrap()
+        });
+        successors.truncate(width);
+
+        frontier = successors
+            .into_iter()
+            .map(|(tour, _)| tour)
+            .collect();
+
+        stats.best_length = frontier
+            .first()
+            .map(|tour| tour.length)
+            .unwrap_or(f32::MAX);
+        let depth = frontier
+            .iter()
+            .map(|tour| tour.visited.len())
+            .max()
+            .unwrap_or(0);
+        maybe_send_search_state(
+            stats,
+            depth,
+            destinations.len(),
+            frontier.len(),
+        );
+
+        send_animation_frame(&frontier.first().unwrap().visited);
+        Frame::new().await;
+    }
+
+    frontier
+        .into_iter()
+        .min_by(|a, b| {
+            a.length.partial_cmp(&b.length).unwrap()
+        })
+        .unwrap()
+        .visited
+}
+
 #[wasm_bindgen]
 pub async fn animate_finding_shortest(
     algorithm: JsValue,
     destinations: JsValue,
+    beam_width: JsValue,
+    metric: JsValue,
 ) -> JsValue {
     if !algorithm.is_string() {
         alert("Algorithm needs to be a string");
         return JsValue::NULL;
     }
 
+    let metric = Metric::from_js(&metric);
+
     match serde_wasm_bindgen::from_value::<Vec<Point>>(
         destinations,
     ) {
         Ok(destinations) => {
+            let algorithm_name = algorithm.as_string().unwrap();
+            let mut stats = Stats::new(&algorithm_name);
+
+            if algorithm_name == "beam" {
+                let shortest_path = animated_beam_search(
+                    &destinations,
+                    beam_width_from(&beam_width),
+                    &mut stats,
+                    metric,
+                )
+                .await;
+
+                return serde_wasm_bindgen::to_value(
+                    &shortest_path,
+                )
+                .unwrap();
+            }
+
             let mut visited =
                 Vec::with_capacity(destinations.len());
             visited.push(0);
@@ -227,9 +962,17 @@ pub async fn animate_finding_shortest(
             let mut shortest_path =
                 Vec::with_capacity(destinations.len());
 
-            (match algorithm.as_string().unwrap().as_str() {
+            let matrix = if algorithm_name == "branch_bound" {
+                DistanceMatrix::unused()
+            } else {
+                DistanceMatrix::build(&destinations, metric)
+            };
+
+            (match algorithm_name.as_str() {
                 "naive" => animated_naive_search,
-                "closest" => animated_closest_search,
+                "closest" => animated_closest_search_rtree,
+                "closest_2opt" => animated_closest_2opt_search,
+                "branch_bound" => animated_branch_bound_search,
                 _ => {
                     alert("Unknown animated algorithm");
                     return JsValue::NULL;
@@ -239,6 +982,9 @@ pub async fn animate_finding_shortest(
                 &mut visited,
                 &mut shortest_path,
                 &mut f32::MAX.clone(),
+                &mut stats,
+                metric,
+                &matrix,
             ).await;
 
             serde_wasm_bindgen::to_value(&shortest_path)
@@ -269,32 +1015,136 @@ fn send_animation_frame(path: &Vec<usize>) {
         .unwrap();
 }
 
+/// Lightweight counters threaded through a search so it can report its
+/// own progress without recomputing anything after the fact.
+struct Stats {
+    algorithm: String,
+    nodes_explored: u64,
+    pruned: u64,
+    best_length: f32,
+    last_report_ms: f64,
+}
+
+impl Stats {
+    fn new(algorithm: &str) -> Stats {
+        Stats {
+            algorithm: algorithm.to_string(),
+            nodes_explored: 0,
+            pruned: 0,
+            best_length: f32::MAX,
+            last_report_ms: 0.,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct SearchState {
+    algorithm: String,
+    depth: usize,
+    frontier_size: usize,
+    best_length: f32,
+    nodes_explored: u64,
+    percent_done: f32,
+}
+
+/// Minimum time between `searchstate` events, so a long run reports
+/// progress without flooding the event loop with one dispatch per
+/// recursion step.
+const STATUS_INTERVAL_MS: f64 = 200.;
+
+/// `maybe_send_search_state` reads the JS performance clock only when
+/// `stats.nodes_explored` is one more than a multiple of this.
+/// `naive_search`/`branch_bound_search` call it once per recursive node,
+/// right after counting that node — tens of millions of nodes past
+/// ~10-12 destinations — so for them this is one clock read every
+/// `CLOCK_SAMPLE_INTERVAL` calls instead of a `performance.now()`
+/// (a WASM<->JS round trip) on every single call, and `searchstate`
+/// dispatch is still throttled to roughly `STATUS_INTERVAL_MS`, just
+/// with coarser timing. `beam_search` counts many nodes per call, so it
+/// reads the clock only on the steps whose node count lands on such a
+/// value.
+const CLOCK_SAMPLE_INTERVAL: u64 = 256;
+
+fn maybe_send_search_state(
+    stats: &mut Stats,
+    depth: usize,
+    total: usize,
+    frontier_size: usize,
+) {
+    if (stats.nodes_explored - 1) % CLOCK_SAMPLE_INTERVAL != 0 {
+        return;
+    }
+
+    let now =
+        window().unwrap().performance().unwrap().now();
+    if now - stats.last_report_ms < STATUS_INTERVAL_MS {
+        return;
+    }
+    stats.last_report_ms = now;
+
+    let state = SearchState {
+        algorithm: stats.algorithm.clone(),
+        depth,
+        frontier_size,
+        best_length: stats.best_length,
+        nodes_explored: stats.nodes_explored,
+        percent_done: if total == 0 {
+            100.
+        } else {
+            depth as f32 / total as f32 * 100.
+        },
+    };
+
+    let mut event = CustomEventInit::new();
+    window()
+        .unwrap()
+        .dispatch_event(
+            &CustomEvent::new_with_event_init_dict(
+                "searchstate",
+                event.detail(
+                    &serde_wasm_bindgen::to_value(&state)
+                        .unwrap(),
+                ),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+}
+
 #[async_recursion(?Send)]
 async fn animated_naive_search(
     destinations: &Vec<Point>,
     visited: &mut Vec<usize>,
     shortest_path: &mut Vec<usize>,
     shortest_length: &mut f32,
+    stats: &mut Stats,
+    _metric: Metric,
+    matrix: &DistanceMatrix,
 ) {
+    stats.nodes_explored += 1;
+    maybe_send_search_state(
+        stats,
+        visited.len(),
+        destinations.len(),
+        1,
+    );
+
     send_animation_frame(visited);
     Frame::new().await;
     if visited.len() >= destinations.len() {
         // Reached the end
         let mut length = 0.;
-        let mut last_dest = destinations.first().unwrap();
-        for dest in visited
-            .iter()
-            .skip(1)
-            .map(|i| destinations.get(*i).unwrap())
-        {
-            length += last_dest.distance(dest);
+        let mut last = visited[0];
+        for &i in visited.iter().skip(1) {
+            length += matrix.at(last, i);
             if length > *shortest_length {
                 return;
             }
-            last_dest = dest;
+            last = i;
         }
 
         *shortest_length = length;
+        stats.best_length = length;
 
         shortest_path.clear();
         shortest_path.extend(visited.iter());
@@ -312,6 +1162,72 @@ async fn animated_naive_search(
             visited,
             shortest_path,
             shortest_length,
+            stats,
+            _metric,
+            matrix,
+        )
+        .await;
+        visited.pop();
+    }
+}
+
+#[async_recursion(?Send)]
+async fn animated_branch_bound_search(
+    destinations: &Vec<Point>,
+    visited: &mut Vec<usize>,
+    shortest_path: &mut Vec<usize>,
+    shortest_length: &mut f32,
+    stats: &mut Stats,
+    metric: Metric,
+    _matrix: &DistanceMatrix,
+) {
+    stats.nodes_explored += 1;
+    maybe_send_search_state(
+        stats,
+        visited.len(),
+        destinations.len(),
+        1,
+    );
+
+    send_animation_frame(visited);
+    Frame::new().await;
+
+    if visited.len() >= destinations.len() {
+        // Reached the end
+        let length = tour_length(destinations, visited, metric);
+        if length < *shortest_length {
+            *shortest_length = length;
+            stats.best_length = length;
+
+            shortest_path.clear();
+            shortest_path.extend(visited.iter());
+        }
+
+        return;
+    }
+
+    let mut key = Vec::new();
+    let bound = tour_length(destinations, visited, metric)
+        + mst_bound(destinations, visited, &mut key, metric);
+    if bound >= *shortest_length {
+        stats.pruned += 1;
+        return;
+    }
+
+    let not_visited: Vec<usize> = (1..destinations.len())
+        .filter(|i| !visited.contains(i))
+        .collect();
+
+    for i in not_visited {
+        visited.push(i);
+        animated_branch_bound_search(
+            destinations,
+            visited,
+            shortest_path,
+            shortest_length,
+            stats,
+            metric,
+            _matrix,
         )
         .await;
         visited.pop();
@@ -324,22 +1240,32 @@ async fn animated_closest_search(
     visited: &mut Vec<usize>,
     shortest_path: &mut Vec<usize>,
     shortest_length: &mut f32,
+    stats: &mut Stats,
+    metric: Metric,
+    matrix: &DistanceMatrix,
 ) {
+    stats.nodes_explored += 1;
+    maybe_send_search_state(
+        stats,
+        visited.len(),
+        destinations.len(),
+        1,
+    );
+
     if visited.len() >= destinations.len() {
         // Reached the end
         shortest_path.extend(visited.iter());
+        stats.best_length =
+            tour_length(destinations, visited, metric);
 
         return;
     }
-    let last =
-        destinations.get(*visited.last().unwrap()).unwrap();
+    let last = *visited.last().unwrap();
 
     let mut not_visited: Vec<(usize, f32)> = (1
         ..destinations.len())
         .filter(|i| !visited.contains(i))
-        .map(|i| {
-            (i, last.distance(destinations.get(i).unwrap()))
-        })
+        .map(|i| (i, matrix.at(last, i)))
         .collect();
 
     for (i, _) in &not_visited {
@@ -358,7 +1284,353 @@ async fn animated_closest_search(
         visited,
         shortest_path,
         shortest_length,
+        stats,
+        metric,
+        matrix,
     )
     .await;
     visited.pop();
 }
+
+/// Animated counterpart of [`closest_search_rtree`]: same R-tree
+/// lookup and fallback conditions, but yields a frame after each step
+/// instead of unwinding straight through to the finished tour.
+async fn animated_closest_search_rtree(
+    destinations: &Vec<Point>,
+    visited: &mut Vec<usize>,
+    shortest_path: &mut Vec<usize>,
+    shortest_length: &mut f32,
+    stats: &mut Stats,
+    metric: Metric,
+    matrix: &DistanceMatrix,
+) {
+    if destinations.len() < RTREE_THRESHOLD
+        || metric != Metric::Euclidean
+    {
+        animated_closest_search(
+            destinations,
+            visited,
+            shortest_path,
+            shortest_length,
+            stats,
+            metric,
+            matrix,
+        )
+        .await;
+        return;
+    }
+
+    let tree: RTree<IndexedPoint> = RTree::bulk_load(
+        destinations
+            .iter()
+            .enumerate()
+            .map(|(index, &point)| IndexedPoint {
+                index,
+                point,
+            })
+            .collect(),
+    );
+
+    animated_closest_search_rtree_step(
+        destinations,
+        &tree,
+        visited,
+        shortest_path,
+        shortest_length,
+        stats,
+        metric,
+    )
+    .await;
+}
+
+#[async_recursion(?Send)]
+async fn animated_closest_search_rtree_step(
+    destinations: &Vec<Point>,
+    tree: &RTree<IndexedPoint>,
+    visited: &mut Vec<usize>,
+    shortest_path: &mut Vec<usize>,
+    shortest_length: &mut f32,
+    stats: &mut Stats,
+    metric: Metric,
+) {
+    stats.nodes_explored += 1;
+    maybe_send_search_state(
+        stats,
+        visited.len(),
+        destinations.len(),
+        1,
+    );
+
+    send_animation_frame(visited);
+    Frame::new().await;
+
+    if visited.len() >= destinations.len() {
+        // Reached the end
+        shortest_path.extend(visited.iter());
+        stats.best_length =
+            tour_length(destinations, visited, metric);
+
+        return;
+    }
+
+    let last =
+        destinations.get(*visited.last().unwrap()).unwrap();
+    let query =
+        [last.x as f32, last.y as f32, last.z as f32];
+
+    let next = tree
+        .nearest_neighbor_iter(&query)
+        .map(|candidate| candidate.index)
+        .find(|index| !visited.contains(index))
+        .unwrap();
+
+    visited.push(next);
+    animated_closest_search_rtree_step(
+        destinations,
+        tree,
+        visited,
+        shortest_path,
+        shortest_length,
+        stats,
+        metric,
+    )
+    .await;
+    visited.pop();
+}
+
+/// Animated counterpart of [`two_opt`]: dispatches a frame after each
+/// accepted reversal so callers can watch crossing edges uncross.
+async fn animated_two_opt(
+    destinations: &Vec<Point>,
+    path: &mut Vec<usize>,
+    shortest_length: &mut f32,
+    metric: Metric,
+) {
+    let mut improved = true;
+    while improved {
+        improved = false;
+
+        for i in 0..path.len() - 1 {
+            for j in (i + 1)..path.len() - 1 {
+                let a = destinations.get(path[i]).unwrap();
+                let b = destinations.get(path[i + 1]).unwrap();
+                let c = destinations.get(path[j]).unwrap();
+                let d = destinations.get(path[j + 1]).unwrap();
+
+                let before = metric.distance(a, b)
+                    + metric.distance(c, d);
+                let after = metric.distance(a, c)
+                    + metric.distance(b, d);
+
+                if after < before {
+                    path[i + 1..=j].reverse();
+                    improved = true;
+                    send_animation_frame(path);
+                    Frame::new().await;
+                }
+            }
+        }
+    }
+
+    *shortest_length = tour_length(destinations, path, metric);
+}
+
+async fn animated_closest_2opt_search(
+    destinations: &Vec<Point>,
+    visited: &mut Vec<usize>,
+    shortest_path: &mut Vec<usize>,
+    shortest_length: &mut f32,
+    stats: &mut Stats,
+    metric: Metric,
+    matrix: &DistanceMatrix,
+) {
+    animated_closest_search_rtree(
+        destinations,
+        visited,
+        shortest_path,
+        shortest_length,
+        stats,
+        metric,
+        matrix,
+    )
+    .await;
+    animated_two_opt(
+        destinations,
+        shortest_path,
+        shortest_length,
+        metric,
+    )
+    .await;
+    stats.best_length = *shortest_length;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    /// Corners of a 10x10 square, starting at the origin.
+    fn square_points() -> Vec<Point> {
+        vec![
+            Point { x: 0, y: 0, z: 0 },
+            Point { x: 10, y: 0, z: 0 },
+            Point { x: 10, y: 10, z: 0 },
+            Point { x: 0, y: 10, z: 0 },
+        ]
+    }
+
+    #[wasm_bindgen_test]
+    fn mst_bound_never_exceeds_the_true_remaining_cost() {
+        let destinations = square_points();
+        let metric = Metric::Euclidean;
+        let visited = vec![0];
+        let mut key = Vec::new();
+
+        let bound =
+            mst_bound(&destinations, &visited, &mut key, metric);
+
+        // True remaining cost from the origin: walk the other three
+        // corners around the square, e.g. 10 + 10 + 10 = 30.
+        assert!(bound <= 30. + 1e-3);
+    }
+
+    #[wasm_bindgen_test]
+    fn branch_bound_matches_naive_on_a_square() {
+        let destinations = square_points();
+        let metric = Metric::Euclidean;
+        let matrix = DistanceMatrix::build(&destinations, metric);
+
+        let mut naive_stats = Stats::new("naive");
+        naive_search(
+            &destinations,
+            &mut vec![0],
+            &mut Vec::new(),
+            &mut f32::MAX.clone(),
+            &mut naive_stats,
+            metric,
+            &matrix,
+        );
+
+        let mut bb_stats = Stats::new("branch_bound");
+        branch_bound_search(
+            &destinations,
+            &mut vec![0],
+            &mut Vec::new(),
+            &mut f32::MAX.clone(),
+            &mut bb_stats,
+            metric,
+            &matrix,
+        );
+
+        assert_eq!(naive_stats.best_length, bb_stats.best_length);
+    }
+
+    #[wasm_bindgen_test]
+    fn two_opt_uncrosses_a_crossed_path() {
+        // 0 -> 1 -> 2 -> 3 crosses itself; 0 -> 2 -> 1 -> 3 does not
+        // and is shorter.
+        let destinations = vec![
+            Point { x: 0, y: 0, z: 0 },
+            Point { x: 10, y: 10, z: 0 },
+            Point { x: 10, y: 0, z: 0 },
+            Point { x: 0, y: 10, z: 0 },
+        ];
+        let metric = Metric::Euclidean;
+        let mut path = vec![0, 1, 2, 3];
+        let before = tour_length(&destinations, &path, metric);
+        let mut length = before;
+
+        two_opt(&destinations, &mut path, &mut length, metric);
+
+        assert!(length < before);
+        assert_eq!(
+            tour_length(&destinations, &path, metric),
+            length
+        );
+    }
+
+    /// `n` destinations zig-zagging along the x axis; tests pass an `n`
+    /// above `RTREE_THRESHOLD`.
+    fn grid_points(n: usize) -> Vec<Point> {
+        (0..n)
+            .map(|i| Point {
+                x: i as i32,
+                y: (i % 3) as i32,
+                z: 0,
+            })
+            .collect()
+    }
+
+    #[wasm_bindgen_test]
+    fn rtree_falls_back_to_brute_force_for_non_euclidean_metrics() {
+        let destinations = grid_points(RTREE_THRESHOLD + 1);
+        let metric = Metric::Manhattan;
+        let matrix = DistanceMatrix::build(&destinations, metric);
+
+        let mut rtree_stats = Stats::new("closest");
+        let mut rtree_path = Vec::new();
+        closest_search_rtree(
+            &destinations,
+            &mut vec![0],
+            &mut rtree_path,
+            &mut f32::MAX.clone(),
+            &mut rtree_stats,
+            metric,
+            &matrix,
+        );
+
+        let mut brute_stats = Stats::new("closest");
+        let mut brute_path = Vec::new();
+        closest_search(
+            &destinations,
+            &mut vec![0],
+            &mut brute_path,
+            &mut f32::MAX.clone(),
+            &mut brute_stats,
+            metric,
+            &matrix,
+        );
+
+        assert_eq!(rtree_path, brute_path);
+    }
+
+    #[wasm_bindgen_test]
+    fn beam_search_visits_every_destination_exactly_once() {
+        let destinations = square_points();
+        let metric = Metric::Euclidean;
+        let mut stats = Stats::new("beam");
+
+        let path = beam_search(&destinations, 2, &mut stats, metric);
+
+        let mut sorted = path.clone();
+        sorted.sort();
+        assert_eq!(sorted, vec![0, 1, 2, 3]);
+    }
+
+    #[wasm_bindgen_test]
+    fn manhattan_distance_sums_axis_deltas() {
+        let a = Point { x: 0, y: 0, z: 0 };
+        let b = Point { x: 3, y: -4, z: 5 };
+
+        assert_eq!(a.manhattan_distance(&b), 12.);
+    }
+
+    #[wasm_bindgen_test]
+    fn distance_squared_skips_the_sqrt() {
+        let a = Point { x: 0, y: 0, z: 0 };
+        let b = Point { x: 3, y: 4, z: 0 };
+
+        assert_eq!(a.distance_squared(&b), 25.);
+        assert_eq!(a.distance(&b), 5.);
+    }
+
+    #[wasm_bindgen_test]
+    fn point_without_z_defaults_to_zero() {
+        let point: Point =
+            serde_json::from_str(r#"{"x": 1, "y": 2}"#).unwrap();
+
+        assert_eq!(point.z, 0);
+    }
+}